@@ -0,0 +1,10 @@
+/// Determines what a grid container does when a neighbor lookup falls outside its bounds.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BoundaryCondition {
+    /// Out-of-range cells read back a fixed value (see `cell_padding`/`diff_padding`/`flow_padding`).
+    Padding,
+    /// The grid wraps around: moving off one edge re-enters on the opposite edge.
+    Toroidal,
+    /// Out-of-range cells mirror the cell just inside the edge they fell off of.
+    Reflective,
+}
@@ -0,0 +1,129 @@
+use crate::{GridDirection, Neighborhood};
+
+/// One of the six directions a hex cell can have a neighbor in, named by compass point.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    E,
+    NE,
+    NW,
+    W,
+    SW,
+    SE,
+}
+
+impl HexDirection {
+    /// All six directions, in the order used to index `HexWindow`/`Edges`.
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::E,
+        HexDirection::NE,
+        HexDirection::NW,
+        HexDirection::W,
+        HexDirection::SW,
+        HexDirection::SE,
+    ];
+
+    /// The `(dq, dr)` axial offset of a single step in this direction.
+    #[inline]
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            HexDirection::E => (1, 0),
+            HexDirection::NE => (1, -1),
+            HexDirection::NW => (0, -1),
+            HexDirection::W => (-1, 0),
+            HexDirection::SW => (-1, 1),
+            HexDirection::SE => (0, 1),
+        }
+    }
+
+    /// The opposite direction, so that a flow sent `dir` arrives as `dir.inv()`.
+    #[inline]
+    pub fn inv(self) -> Self {
+        match self {
+            HexDirection::E => HexDirection::W,
+            HexDirection::NE => HexDirection::SW,
+            HexDirection::NW => HexDirection::SE,
+            HexDirection::W => HexDirection::E,
+            HexDirection::SW => HexDirection::NE,
+            HexDirection::SE => HexDirection::NW,
+        }
+    }
+}
+
+/// The center cell of a hex grid together with its six neighbors, handed to `Sim::compute`
+/// and `Sim::egress` the same way an `ArrayView2` 3x3 window is handed to a `Neumann` `Sim`.
+///
+/// `Copy`/`Clone` are implemented by hand (rather than derived) so they don't require
+/// `T: Copy`/`T: Clone` -- `HexWindow` only ever holds references to `T`, never an owned one.
+#[derive(Debug)]
+pub struct HexWindow<'a, T> {
+    pub center: &'a T,
+    pub neighbors: [&'a T; 6],
+}
+
+impl<'a, T> Clone for HexWindow<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for HexWindow<'a, T> {}
+
+impl<'a, T> HexWindow<'a, T> {
+    /// Iterate over the center cell followed by all six neighbors.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        std::iter::once(self.center).chain(self.neighbors.iter().copied())
+    }
+}
+
+impl GridDirection for HexDirection {
+    fn all() -> &'static [Self] {
+        &Self::ALL
+    }
+
+    fn neighbor(self, width: usize, height: usize, row: usize, col: usize) -> Option<(usize, usize)> {
+        neighbor_offset(width, height, row, col, self)
+    }
+}
+
+/// A hexagonal neighborhood using axial coordinates, with six neighbors per cell.
+pub enum Hex {}
+
+impl Neighborhood for Hex {
+    type Neighbors<'a, T: 'a> = HexWindow<'a, T>;
+    type Edges<T> = [T; 6];
+    type Direction = HexDirection;
+}
+
+/// Convert row-major offset coordinates (using the "odd-r" horizontal layout) to axial
+/// coordinates `(q, r)`.
+#[inline]
+pub(crate) fn offset_to_axial(row: isize, col: isize) -> (isize, isize) {
+    let q = col - (row - (row & 1)) / 2;
+    (q, row)
+}
+
+/// Convert axial coordinates `(q, r)` back to row-major offset coordinates.
+#[inline]
+pub(crate) fn axial_to_offset(q: isize, r: isize) -> (isize, isize) {
+    let col = q + (r - (r & 1)) / 2;
+    (r, col)
+}
+
+/// Find the offset-coordinate index of the neighbor of `(row, col)` in direction `dir`,
+/// or `None` if it falls outside a `width` by `height` grid.
+pub(crate) fn neighbor_offset(
+    width: usize,
+    height: usize,
+    row: usize,
+    col: usize,
+    dir: HexDirection,
+) -> Option<(usize, usize)> {
+    let (q, r) = offset_to_axial(row as isize, col as isize);
+    let (dq, dr) = dir.delta();
+    let (nrow, ncol) = axial_to_offset(q + dq, r + dr);
+    if nrow < 0 || ncol < 0 || nrow as usize >= height || ncol as usize >= width {
+        None
+    } else {
+        Some((nrow as usize, ncol as usize))
+    }
+}
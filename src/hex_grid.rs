@@ -0,0 +1,107 @@
+use crate::hex::neighbor_offset;
+use crate::{Hex, HexDirection, HexWindow, Sim};
+use ndarray::{Array2, ArrayView2, ArrayViewMut2};
+use std::mem;
+
+/// Represents the state of a hex-grid simulation.
+#[derive(Clone, Debug)]
+pub struct HexGrid<S>
+where
+    S: Sim<Hex>,
+{
+    sim: S,
+    cells: Array2<S::Cell>,
+}
+
+impl<S> HexGrid<S>
+where
+    S: Sim<Hex>,
+{
+    /// Make a new grid with the given cells, stored row-major. Neighbors that fall off the
+    /// edge of the grid read back `Sim::cell_padding`/`diff_padding`/`flow_padding`.
+    pub fn new(sim: S, cells: Array2<S::Cell>) -> Self {
+        let dims = cells.dim();
+        assert!(
+            dims.0 >= 1 && dims.1 >= 1,
+            "grid is empty, which isnt allowed"
+        );
+        Self { sim, cells }
+    }
+
+    /// Get a view of the cells on the grid.
+    pub fn cells(&self) -> ArrayView2<'_, S::Cell> {
+        self.cells.view()
+    }
+
+    /// Get a mutable view of the cells on the grid.
+    pub fn cells_mut(&mut self) -> ArrayViewMut2<'_, S::Cell> {
+        self.cells.view_mut()
+    }
+
+    /// Run one full compute/egress/ingress cycle over the whole grid.
+    ///
+    /// Like `SquareGrid::cycle`, `compute` and `egress` write their output (diffs, then flows)
+    /// into fresh buffers, and `egress` sees its neighbors only through those diffs, so old
+    /// board state is never read after any new cell has been produced.
+    pub fn cycle(&mut self) {
+        let diffs = self.compute_diffs();
+        let edges = self.perform_egress(&diffs);
+        self.perform_ingress(edges);
+    }
+
+    fn compute_diffs(&self) -> Array2<S::Diff> {
+        let (height, width) = self.cells.dim();
+        let sim = &self.sim;
+        Array2::from_shape_fn((height, width), |(row, col)| {
+            let padding = sim.cell_padding();
+            let neighbors = HexDirection::ALL.map(|dir| {
+                neighbor_offset(width, height, row, col, dir)
+                    .map(|(nrow, ncol)| &self.cells[(nrow, ncol)])
+                    .unwrap_or(&padding)
+            });
+            let window = HexWindow {
+                center: &self.cells[(row, col)],
+                neighbors,
+            };
+            sim.compute(window)
+        })
+    }
+
+    fn perform_egress(&mut self, diffs: &Array2<S::Diff>) -> Array2<[S::Flow; 6]> {
+        let (height, width) = self.cells.dim();
+        let sim = &self.sim;
+        let cells = &mut self.cells;
+        Array2::from_shape_fn((height, width), |(row, col)| {
+            let diff_padding = sim.diff_padding();
+            let neighbors = HexDirection::ALL.map(|dir| {
+                neighbor_offset(width, height, row, col, dir)
+                    .map(|(nrow, ncol)| &diffs[(nrow, ncol)])
+                    .unwrap_or(&diff_padding)
+            });
+            let window = HexWindow {
+                center: &diffs[(row, col)],
+                neighbors,
+            };
+            sim.egress(&mut cells[(row, col)], window)
+        })
+    }
+
+    fn perform_ingress(&mut self, mut edges: Array2<[S::Flow; 6]>) {
+        let (height, width) = self.cells.dim();
+        let sim = &self.sim;
+        for row in 0..height {
+            for col in 0..width {
+                let incoming = HexDirection::ALL.map(|dir| {
+                    match neighbor_offset(width, height, row, col, dir) {
+                        Some((nrow, ncol)) => mem::replace(
+                            &mut edges[(nrow, ncol)][dir.inv() as usize],
+                            sim.flow_padding(),
+                        ),
+                        None => sim.flow_padding(),
+                    }
+                });
+                sim.ingress(&mut self.cells[(row, col)], incoming);
+            }
+        }
+    }
+}
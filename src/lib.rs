@@ -2,21 +2,33 @@
 //!
 //! The new generics introduced in gridsim 0.2.0 make it possible to implement hex grids,
 //! rhombic dodecahedral honeycombs(in its multiple tight-pack layer patterns), square grids, cube grids,
-//! and even n-dimensional grids, but they are currently not yet implemented.
-
-#![feature(type_alias_impl_trait)]
-#![feature(generic_associated_types)]
-#![allow(incomplete_features)]
+//! and even n-dimensional grids. Square (`SquareGrid`) and hex (`HexGrid`) grids are implemented
+//! so far; the rest are currently not yet implemented.
 
+mod boundary;
+mod diffusion;
+mod hex;
+mod hex_grid;
 mod neumann;
+mod square_direction;
 mod square_grid;
+mod square_io;
+mod traversal;
 
+pub use boundary::*;
+pub use diffusion::*;
+pub use hex::*;
+pub use hex_grid::*;
 pub use neumann::*;
+pub use square_direction::*;
 pub use square_grid::*;
+pub use traversal::*;
 
 pub trait Neighborhood {
     type Neighbors<'a, T: 'a>;
     type Edges<T>;
+    /// Names a single direction to one of this neighborhood's neighbors.
+    type Direction;
 }
 
 /// Defines a simulation for complicated things that have too much state to abandon on the next cycle.
@@ -59,4 +71,25 @@ where
 
     /// The flow used as padding.
     fn flow_padding(&self) -> Self::Flow;
+
+    /// Map a neighbor lookup that left the grid from `from` heading `dir` onto some in-bounds
+    /// `(index, direction)` pair instead, letting the grid fold onto itself -- enough to stitch
+    /// a flat grid into a cube net (six faces wired up at their edges, with a rotated direction
+    /// across the seam) or to wire up arbitrary "portal" connections between distant cells.
+    ///
+    /// The grid's gather step calls this whenever a neighbor lookup would otherwise fall
+    /// outside its bounds. Returning `None` (the default) leaves the grid's own boundary
+    /// handling (e.g. `BoundaryCondition`) in charge instead.
+    ///
+    /// `SquareGrid` honors this for both the compute-time cell substitution and the `Flow`
+    /// exchange: a seam cell's incoming flow from `dir` is sourced from the target cell's own
+    /// outgoing flow in its `rotated` direction instead of the padding ring.
+    fn topology(
+        &self,
+        from: (usize, usize),
+        dir: N::Direction,
+    ) -> Option<((usize, usize), N::Direction)> {
+        let _ = (from, dir);
+        None
+    }
 }
@@ -1,4 +1,4 @@
-use crate::Neighborhood;
+use crate::{Neighborhood, SquareDirection};
 use ndarray::ArrayView2;
 
 pub enum Neumann {}
@@ -6,4 +6,5 @@ pub enum Neumann {}
 impl Neighborhood for Neumann {
     type Neighbors<'a, T: 'a> = ArrayView2<'a, T>;
     type Edges<T> = [T; 8];
+    type Direction = SquareDirection;
 }
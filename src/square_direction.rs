@@ -0,0 +1,58 @@
+use crate::GridDirection;
+use SquareDirection::*;
+
+/// One of the 8 directions in a `Neumann` square-grid window, in the same index order
+/// `SquareGrid`'s internal flow exchange uses: starting east and going counter-clockwise, with
+/// each direction's opposite 4 slots away.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SquareDirection {
+    E,
+    Ne,
+    N,
+    Nw,
+    W,
+    Sw,
+    S,
+    Se,
+}
+
+impl SquareDirection {
+    pub const ALL: [SquareDirection; 8] = [E, Ne, N, Nw, W, Sw, S, Se];
+
+    /// The `(row, col)` offset of a single step in this direction.
+    #[inline]
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            E => (0, 1),
+            Ne => (-1, 1),
+            N => (-1, 0),
+            Nw => (-1, -1),
+            W => (0, -1),
+            Sw => (1, -1),
+            S => (1, 0),
+            Se => (1, 1),
+        }
+    }
+
+    /// The opposite direction.
+    #[inline]
+    pub fn inv(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl GridDirection for SquareDirection {
+    fn all() -> &'static [Self] {
+        &Self::ALL
+    }
+
+    fn neighbor(self, width: usize, height: usize, row: usize, col: usize) -> Option<(usize, usize)> {
+        let (dr, dc) = self.offset();
+        let (nrow, ncol) = (row as isize + dr, col as isize + dc);
+        if nrow < 0 || ncol < 0 || nrow as usize >= height || ncol as usize >= width {
+            None
+        } else {
+            Some((nrow as usize, ncol as usize))
+        }
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(clippy::reversed_empty_ranges)]
 
-use crate::{Neumann, Sim};
+use crate::{BoundaryCondition, Neumann, Sim, SquareDirection};
 use ndarray::{par_azip, s, Array2, ArrayView2, ArrayViewMut2};
 use std::{
     cell::UnsafeCell,
@@ -16,6 +16,7 @@ where
 {
     sim: S,
     cells: Array2<S::Cell>,
+    boundary: BoundaryCondition,
 }
 
 impl<S> SquareGrid<S>
@@ -23,8 +24,17 @@ where
     S: Sim<Neumann>,
     S::Cell: Send,
 {
-    /// Make a new grid with the given cells.
-    pub fn new(sim: S, mut original_cells: Array2<S::Cell>) -> Self {
+    /// Make a new grid with the given cells, using `BoundaryCondition::Padding` at the edges.
+    pub fn new(sim: S, original_cells: Array2<S::Cell>) -> Self {
+        Self::new_with_boundary(sim, original_cells, BoundaryCondition::Padding)
+    }
+
+    /// Make a new grid with the given cells and the given edge behavior.
+    pub fn new_with_boundary(
+        sim: S,
+        mut original_cells: Array2<S::Cell>,
+        boundary: BoundaryCondition,
+    ) -> Self {
         let dims = original_cells.dim();
         assert!(
             dims.0 >= 1 && dims.1 >= 1,
@@ -35,7 +45,11 @@ where
         par_azip!((dest in &mut cells.slice_mut(s![1..-1, 1..-1]), cell in &mut original_cells) {
             mem::swap(dest, cell);
         });
-        Self { sim, cells }
+        Self {
+            sim,
+            cells,
+            boundary,
+        }
     }
 
     /// Get view of cells on the grid.
@@ -53,16 +67,125 @@ where
 impl<S> SquareGrid<S>
 where
     S: Sim<Neumann> + Sync,
-    S::Cell: Send + Sync,
-    S::Diff: Send + Sync,
+    S::Cell: Clone + Send + Sync,
+    S::Diff: Clone + Send + Sync,
     S::Flow: Send,
 {
-    pub fn step_parallel(&mut self) {
-        let diffs = self.compute_diffs();
+    /// Run one full compute/egress/ingress cycle over the whole grid in parallel.
+    ///
+    /// Each phase is double-buffered: `compute` is read-only and produces a fresh
+    /// `Diff` array from the current cells, `egress` mutates each cell from its own
+    /// diffs (and the diffs of its neighbors) while collecting the flow bound for
+    /// each neighbor, and only then does `ingress` fold those flows into the cells.
+    /// Because the diffs and flows always live in their own arrays, old board state
+    /// is never read after any new cell has been produced, so there's no
+    /// "cannot borrow `*self` as mutable more than once" conflict to work around.
+    pub fn cycle(&mut self) {
+        self.refresh_boundary();
+        self.refresh_topology();
+        let mut diffs = self.compute_diffs();
+        self.refresh_diffs_boundary(&mut diffs);
         let flows = self.perform_egress(diffs.view());
         self.perform_ingress(flows);
     }
 
+    /// Refresh the padding ring around the grid according to `self.boundary`, so that the
+    /// subsequent `compute`/`egress` windows see the right edge behavior without needing to
+    /// special-case indexing. For `Padding` the ring keeps the `cell_padding` values it was
+    /// built with (apart from any cells `refresh_topology` overwrites), so there's nothing to
+    /// do for it here.
+    fn refresh_boundary(&mut self) {
+        let (h, w) = self.cells.dim();
+        match self.boundary {
+            BoundaryCondition::Padding => {}
+            BoundaryCondition::Toroidal => {
+                for x in 1..w - 1 {
+                    self.cells[(0, x)] = self.cells[(h - 2, x)].clone();
+                    self.cells[(h - 1, x)] = self.cells[(1, x)].clone();
+                }
+                for y in 1..h - 1 {
+                    self.cells[(y, 0)] = self.cells[(y, w - 2)].clone();
+                    self.cells[(y, w - 1)] = self.cells[(y, 1)].clone();
+                }
+                self.cells[(0, 0)] = self.cells[(h - 2, w - 2)].clone();
+                self.cells[(0, w - 1)] = self.cells[(h - 2, 1)].clone();
+                self.cells[(h - 1, 0)] = self.cells[(1, w - 2)].clone();
+                self.cells[(h - 1, w - 1)] = self.cells[(1, 1)].clone();
+            }
+            BoundaryCondition::Reflective => {
+                for x in 1..w - 1 {
+                    self.cells[(0, x)] = self.cells[(1, x)].clone();
+                    self.cells[(h - 1, x)] = self.cells[(h - 2, x)].clone();
+                }
+                for y in 1..h - 1 {
+                    self.cells[(y, 0)] = self.cells[(y, 1)].clone();
+                    self.cells[(y, w - 1)] = self.cells[(y, w - 2)].clone();
+                }
+                self.cells[(0, 0)] = self.cells[(1, 1)].clone();
+                self.cells[(0, w - 1)] = self.cells[(1, w - 2)].clone();
+                self.cells[(h - 1, 0)] = self.cells[(h - 2, 1)].clone();
+                self.cells[(h - 1, w - 1)] = self.cells[(h - 2, w - 2)].clone();
+            }
+        }
+    }
+
+    /// Let `sim.topology` override the padding ring at any edge cell whose out-of-bounds
+    /// neighbor it wants to fold onto a specific in-bounds cell, overwriting whatever
+    /// `refresh_boundary` already put there.
+    fn refresh_topology(&mut self) {
+        let (h, w) = self.cells.dim();
+        let (interior_h, interior_w) = (h - 2, w - 2);
+        for row in 0..interior_h {
+            for col in 0..interior_w {
+                for dir in SquareDirection::ALL {
+                    let (dr, dc) = dir.offset();
+                    let (nr, nc) = (row as isize + dr, col as isize + dc);
+                    if nr < 0 || nc < 0 || nr as usize >= interior_h || nc as usize >= interior_w {
+                        if let Some(((tr, tc), _rotated)) = self.sim.topology((row, col), dir) {
+                            let value = self.cells[(tr + 1, tc + 1)].clone();
+                            let (pr, pc) = ((row as isize + 1 + dr) as usize, (col as isize + 1 + dc) as usize);
+                            self.cells[(pr, pc)] = value;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// For an edge cell `(row, col)` whose neighbor in `dir` falls outside the grid, find the
+    /// `(index, Direction)` that cell's incoming flow from `dir` should come from instead of the
+    /// padding ring: either wherever `sim.topology` folds it onto, or -- on a `Toroidal` grid,
+    /// when `sim.topology` declines -- the cell wrapped around to the opposite edge, via the
+    /// inverse direction (the same "my outgoing `dir` is their incoming `dir.inv()`" rule every
+    /// plain adjacency uses, just run from the other side: what `(row, col)` receives from `dir`
+    /// is whatever the wrapped cell sent out its own `dir.inv()`).
+    ///
+    /// Note this can legitimately collide with a target cell's real geometric neighbor in that
+    /// same direction (most directly when `sim.topology` folds onto a cell that isn't itself on
+    /// the seam) -- same as `refresh_topology` overwriting whatever `refresh_boundary` put in the
+    /// padding ring, the topology connection wins.
+    fn reroute_boundary_flow(
+        &self,
+        row: usize,
+        col: usize,
+        dir: SquareDirection,
+    ) -> Option<((usize, usize), SquareDirection)> {
+        if let Some(target) = self.sim.topology((row, col), dir) {
+            return Some(target);
+        }
+        if self.boundary == BoundaryCondition::Toroidal {
+            let (h, w) = self.cells.dim();
+            let (interior_h, interior_w) = (h - 2, w - 2);
+            let (dr, dc) = dir.offset();
+            let target = (
+                (row as isize + dr).rem_euclid(interior_h as isize) as usize,
+                (col as isize + dc).rem_euclid(interior_w as isize) as usize,
+            );
+            return Some((target, dir.inv()));
+        }
+        None
+    }
+
     fn compute_diffs(&self) -> Array2<S::Diff> {
         let mut diffs = Array2::from_shape_simple_fn(self.cells.dim(), || self.sim.diff_padding());
         par_azip!((diff in diffs.slice_mut(s![1..-1, 1..-1]), cell in self.cells.windows((3, 3))) {
@@ -71,6 +194,31 @@ where
         diffs
     }
 
+    /// Mirror `refresh_boundary`'s wrap onto the `diffs` ring for a `Toroidal` grid, so an edge
+    /// cell's `egress` window sees the real wrapped neighbor's diff instead of `diff_padding` --
+    /// `compute_diffs` only ever fills the interior, so without this the ghost ring it leaves
+    /// behind never reflects the wrap-around `refresh_boundary` already applied to `self.cells`.
+    /// `Padding` has nothing to wrap, and `Reflective` doesn't reroute flow across its edge either,
+    /// so neither needs anything here.
+    fn refresh_diffs_boundary(&self, diffs: &mut Array2<S::Diff>) {
+        if self.boundary != BoundaryCondition::Toroidal {
+            return;
+        }
+        let (h, w) = diffs.dim();
+        for x in 1..w - 1 {
+            diffs[(0, x)] = diffs[(h - 2, x)].clone();
+            diffs[(h - 1, x)] = diffs[(1, x)].clone();
+        }
+        for y in 1..h - 1 {
+            diffs[(y, 0)] = diffs[(y, w - 2)].clone();
+            diffs[(y, w - 1)] = diffs[(y, 1)].clone();
+        }
+        diffs[(0, 0)] = diffs[(h - 2, w - 2)].clone();
+        diffs[(0, w - 1)] = diffs[(h - 2, 1)].clone();
+        diffs[(h - 1, 0)] = diffs[(1, w - 2)].clone();
+        diffs[(h - 1, w - 1)] = diffs[(1, 1)].clone();
+    }
+
     fn perform_egress(
         &mut self,
         diffs: ArrayView2<'_, S::Diff>,
@@ -92,6 +240,31 @@ where
             *flow.get_mut() = sim.egress(cell, diffs);
         });
 
+        // Before the plain-adjacency exchange below sweeps every edge cell's outgoing flow into
+        // the padding ring (where `perform_ingress` just drops it), pull out the rerouted
+        // target's own outgoing flow in its `rotated` direction -- the value the edge cell should
+        // receive from `dir` -- replacing it with `flow_padding` so the sweep still has a
+        // well-formed (and now harmless) value to move there instead.
+        let (interior_h, interior_w) = (self.cells.dim().0 - 2, self.cells.dim().1 - 2);
+        let mut rerouted = Vec::new();
+        for row in 0..interior_h {
+            for col in 0..interior_w {
+                for dir in SquareDirection::ALL {
+                    let (dr, dc) = dir.offset();
+                    let (nr, nc) = (row as isize + dr, col as isize + dc);
+                    if nr < 0 || nc < 0 || nr as usize >= interior_h || nc as usize >= interior_w {
+                        if let Some((target, rotated)) = self.reroute_boundary_flow(row, col, dir) {
+                            let slot = unsafe {
+                                &mut (*flows[(target.0 + 1, target.1 + 1)].get())[rotated as usize]
+                            };
+                            let value = mem::replace(slot, self.sim.flow_padding());
+                            rerouted.push((row + 1, col + 1, dir, value));
+                        }
+                    }
+                }
+            }
+        }
+
         unsafe fn exchange_chunk<T>(chunk: ArrayViewMut2<'_, ManuallyDrop<UnsafeCell<[T; 8]>>>) {
             let top_left = &mut *chunk[(0, 0)].get();
             let top_right = &mut *chunk[(0, 1)].get();
@@ -120,6 +293,13 @@ where
             });
         }
 
+        // Deliver every rerouted flow into the edge cell's own slot now that the plain-adjacency
+        // sweep above is done and won't clobber it.
+        for (row, col, dir, value) in rerouted {
+            let slot = unsafe { &mut (*flows[(row, col)].get())[dir as usize] };
+            *slot = value;
+        }
+
         flows
     }
 
@@ -0,0 +1,40 @@
+use crate::{Neumann, Sim, SquareGrid};
+use ndarray::{Array2, Axis};
+
+impl<S> SquareGrid<S>
+where
+    S: Sim<Neumann>,
+    S::Cell: Send,
+{
+    /// Parse a grid out of a text block, one line per row, decoding each character to a cell
+    /// with `char_to_cell`. Ragged lines are padded out to the width of the longest line using
+    /// `Sim::cell_padding`, and the grid's dimensions come from the parsed text.
+    pub fn from_str_with(
+        sim: S,
+        text: &str,
+        mut char_to_cell: impl FnMut(char) -> S::Cell,
+    ) -> Self {
+        let rows: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+        let height = rows.len();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        assert!(
+            height >= 1 && width >= 1,
+            "cannot build a grid from empty text"
+        );
+        let cells = Array2::from_shape_fn((height, width), |(y, x)| match rows[y].get(x) {
+            Some(&c) => char_to_cell(c),
+            None => sim.cell_padding(),
+        });
+        Self::new(sim, cells)
+    }
+
+    /// Render the grid back to a string, one line per row, encoding each cell with
+    /// `cell_to_char`. The inverse of `from_str_with`.
+    pub fn to_string_with(&self, mut cell_to_char: impl FnMut(&S::Cell) -> char) -> String {
+        self.cells()
+            .axis_iter(Axis(0))
+            .map(|row| row.iter().map(&mut cell_to_char).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,91 @@
+use ndarray::{Array2, ArrayView2};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A direction type that can enumerate all of its variants and resolve itself to a neighboring
+/// `(row, col)` on a `width` by `height` grid, which is all `flood_fill`/`bfs_distances`/
+/// `connected_components` need to stay generic over `SquareDirection` and `HexDirection` alike.
+pub trait GridDirection: Copy + Eq + 'static {
+    /// Every direction this type can name, in no particular order.
+    fn all() -> &'static [Self];
+
+    /// The neighbor of `(row, col)` one step in this direction, or `None` if it falls outside
+    /// a `width` by `height` grid.
+    fn neighbor(self, width: usize, height: usize, row: usize, col: usize) -> Option<(usize, usize)>;
+}
+
+/// Flood-fill outward from `seed`, following `D`'s directions and only crossing into a neighbor
+/// cell when `connected(from, to)` says the two cells belong together. Returns every cell
+/// reached, including `seed` itself.
+pub fn flood_fill<T, D: GridDirection>(
+    cells: ArrayView2<'_, T>,
+    seed: (usize, usize),
+    connected: impl Fn(&T, &T) -> bool,
+) -> HashSet<(usize, usize)> {
+    let (height, width) = cells.dim();
+    let mut visited = HashSet::new();
+    let mut frontier = VecDeque::new();
+    visited.insert(seed);
+    frontier.push_back(seed);
+    while let Some((row, col)) = frontier.pop_front() {
+        for dir in D::all() {
+            if let Some(next) = dir.neighbor(width, height, row, col) {
+                if !visited.contains(&next) && connected(&cells[(row, col)], &cells[next]) {
+                    visited.insert(next);
+                    frontier.push_back(next);
+                }
+            }
+        }
+    }
+    visited
+}
+
+/// Breadth-first distance, in steps, from `seed` to every cell reachable through `connected`
+/// neighbors. Unreached cells are absent from the map; `seed` maps to `0`.
+pub fn bfs_distances<T, D: GridDirection>(
+    cells: ArrayView2<'_, T>,
+    seed: (usize, usize),
+    connected: impl Fn(&T, &T) -> bool,
+) -> HashMap<(usize, usize), usize> {
+    let (height, width) = cells.dim();
+    let mut distances = HashMap::new();
+    let mut frontier = VecDeque::new();
+    distances.insert(seed, 0);
+    frontier.push_back(seed);
+    while let Some((row, col)) = frontier.pop_front() {
+        let dist = distances[&(row, col)];
+        for dir in D::all() {
+            if let Some(next) = dir.neighbor(width, height, row, col) {
+                if !distances.contains_key(&next) && connected(&cells[(row, col)], &cells[next]) {
+                    distances.insert(next, dist + 1);
+                    frontier.push_back(next);
+                }
+            }
+        }
+    }
+    distances
+}
+
+/// Label every cell with the index of its connected component, where two adjacent cells belong
+/// to the same component exactly when `connected` says so. Components are numbered from `0` in
+/// the order their first cell (in row-major order) is discovered.
+pub fn connected_components<T, D: GridDirection>(
+    cells: ArrayView2<'_, T>,
+    connected: impl Fn(&T, &T) -> bool,
+) -> Array2<usize> {
+    let (height, width) = cells.dim();
+    let mut labels = Array2::from_elem((height, width), usize::MAX);
+    let mut next_label = 0;
+    for row in 0..height {
+        for col in 0..width {
+            if labels[(row, col)] != usize::MAX {
+                continue;
+            }
+            let label = next_label;
+            next_label += 1;
+            for cell in flood_fill::<T, D>(cells, (row, col), &connected) {
+                labels[cell] = label;
+            }
+        }
+    }
+    labels
+}
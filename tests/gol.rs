@@ -1,4 +1,4 @@
-use gridsim::{Neumann, Sim, SquareGrid};
+use gridsim::{BoundaryCondition, Neumann, Sim, SquareGrid};
 use ndarray::ArrayView2;
 
 /// Conway's Game of Life
@@ -47,17 +47,33 @@ mod tests {
     fn gol_blinker() {
         let mut grid = SquareGrid::new(
             Gol,
-            Array2::from_shape_fn((5, 5), |(y, x)| y == 2 && x >= 1 && x <= 3),
+            Array2::from_shape_fn((5, 5), |(y, x)| y == 2 && (1..=3).contains(&x)),
         );
-        grid.step_parallel();
+        grid.cycle();
         assert_eq!(
             grid.cells(),
-            Array2::from_shape_fn((5, 5), |(y, x)| x == 2 && y >= 1 && y <= 3)
+            Array2::from_shape_fn((5, 5), |(y, x)| x == 2 && (1..=3).contains(&y))
         );
-        grid.step_parallel();
+        grid.cycle();
         assert_eq!(
             grid.cells(),
-            Array2::from_shape_fn((5, 5), |(y, x)| y == 2 && x >= 1 && x <= 3)
+            Array2::from_shape_fn((5, 5), |(y, x)| y == 2 && (1..=3).contains(&x))
+        );
+    }
+
+    #[test]
+    fn gol_blinker_toroidal() {
+        // A blinker that straddles the right/left edge should keep oscillating as if
+        // the three cells were contiguous, since the boundary wraps around.
+        let mut grid = SquareGrid::new_with_boundary(
+            Gol,
+            Array2::from_shape_fn((5, 5), |(y, x)| y == 2 && (x == 3 || x == 4 || x == 0)),
+            BoundaryCondition::Toroidal,
+        );
+        grid.cycle();
+        assert_eq!(
+            grid.cells(),
+            Array2::from_shape_fn((5, 5), |(y, x)| x == 4 && (1..=3).contains(&y))
         );
     }
 }
@@ -0,0 +1,48 @@
+use gridsim::{Hex, HexGrid, HexWindow, Sim};
+use ndarray::Array2;
+
+/// Sums up how many of a cell's six neighbors are alive (padding counts as dead).
+#[derive(Debug)]
+pub struct NeighborCount;
+
+impl Sim<Hex> for NeighborCount {
+    type Cell = u32;
+    type Diff = u32;
+    type Flow = ();
+
+    fn compute(&self, cells: HexWindow<'_, u32>) -> u32 {
+        cells.neighbors.iter().copied().sum()
+    }
+
+    fn egress(&self, cell: &mut Self::Cell, diffs: HexWindow<'_, u32>) -> [(); 6] {
+        *cell = *diffs.center;
+        [(); 6]
+    }
+
+    fn ingress(&self, _: &mut Self::Cell, _: [(); 6]) {}
+
+    fn cell_padding(&self) -> Self::Cell {
+        0
+    }
+
+    fn diff_padding(&self) -> Self::Diff {
+        0
+    }
+
+    fn flow_padding(&self) -> Self::Flow {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_neighbor_counts() {
+        let mut grid = HexGrid::new(NeighborCount, Array2::from_elem((3, 3), 1u32));
+        grid.cycle();
+        // The center cell has all six neighbors in-bounds.
+        assert_eq!(grid.cells()[(1, 1)], 6);
+        // A corner cell is missing some neighbors off the edge of the grid.
+        assert!(grid.cells()[(0, 0)] < 6);
+    }
+}
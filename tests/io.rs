@@ -0,0 +1,76 @@
+use gridsim::{Neumann, Sim, SquareGrid};
+use ndarray::ArrayView2;
+
+/// Conway's Game of Life, reused here to exercise ASCII loading/rendering.
+#[derive(Debug)]
+pub struct Gol;
+
+impl Sim<Neumann> for Gol {
+    type Cell = bool;
+    type Diff = bool;
+    type Flow = ();
+
+    fn compute(&self, cells: ArrayView2<'_, bool>) -> bool {
+        let n = cells.iter().filter(|&&c| c).count();
+        if cells[(1, 1)] {
+            (3..=4).contains(&n)
+        } else {
+            n == 3
+        }
+    }
+
+    fn egress(&self, cell: &mut Self::Cell, diffs: ArrayView2<'_, bool>) -> [(); 8] {
+        *cell = diffs[(1, 1)];
+        [(); 8]
+    }
+
+    fn ingress(&self, _: &mut Self::Cell, _: [(); 8]) {}
+
+    fn cell_padding(&self) -> Self::Cell {
+        false
+    }
+
+    fn diff_padding(&self) -> Self::Diff {
+        false
+    }
+
+    fn flow_padding(&self) -> Self::Flow {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_round_trips() {
+        let grid = SquareGrid::from_str_with(Gol, ".#.\n.#.\n.#.", |c| c == '#');
+        assert_eq!(
+            grid.to_string_with(|&c| if c { '#' } else { '.' }),
+            ".#.\n.#.\n.#."
+        );
+    }
+
+    #[test]
+    fn from_str_pads_ragged_lines() {
+        // The second line is shorter, so it should be padded out with dead cells.
+        let grid = SquareGrid::from_str_with(Gol, "###\n#\n###", |c| c == '#');
+        assert_eq!(
+            grid.to_string_with(|&c| if c { '#' } else { '.' }),
+            "###\n#..\n###"
+        );
+    }
+
+    #[test]
+    fn from_str_blinker_still_oscillates() {
+        let mut grid = SquareGrid::from_str_with(
+            Gol,
+            ".....\n.....\n.###.\n.....\n.....",
+            |c| c == '#',
+        );
+        grid.cycle();
+        assert_eq!(
+            grid.to_string_with(|&c| if c { '#' } else { '.' }),
+            ".....\n..#..\n..#..\n..#..\n....."
+        );
+    }
+}
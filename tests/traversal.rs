@@ -0,0 +1,56 @@
+use gridsim::{bfs_distances, connected_components, flood_fill, HexDirection, SquareDirection};
+use ndarray::Array2;
+
+/// Two separate `true` blobs: a 2x2 square at the top-left corner, and a lone cell at `(3,3)`
+/// placed a Chebyshev distance of 2 away so it isn't reachable even through `SquareDirection`'s
+/// diagonals.
+fn two_blobs() -> Array2<bool> {
+    Array2::from_shape_fn((4, 4), |(row, col)| {
+        matches!((row, col), (0, 0) | (0, 1) | (1, 0) | (1, 1) | (3, 3))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flood_fill_stops_at_region_boundary() {
+        let cells = two_blobs();
+        let region = flood_fill::<_, SquareDirection>(cells.view(), (0, 0), |a, b| a == b);
+        assert_eq!(region.len(), 4);
+        assert!(region.contains(&(0, 0)));
+        assert!(region.contains(&(0, 1)));
+        assert!(region.contains(&(1, 0)));
+        assert!(region.contains(&(1, 1)));
+        assert!(!region.contains(&(3, 3)));
+    }
+
+    #[test]
+    fn bfs_distances_count_steps() {
+        let cells = Array2::from_elem((1, 5), true);
+        let distances = bfs_distances::<_, SquareDirection>(cells.view(), (0, 0), |_, _| true);
+        assert_eq!(distances[&(0, 0)], 0);
+        assert_eq!(distances[&(0, 4)], 4);
+    }
+
+    #[test]
+    fn connected_components_labels_each_blob_separately() {
+        let cells = two_blobs();
+        let labels = connected_components::<_, SquareDirection>(cells.view(), |a, b| a == b);
+        // The four `true` cells in the top-left square share a label...
+        assert_eq!(labels[(0, 0)], labels[(1, 1)]);
+        // ...but the lone `true` cell in the bottom-right corner, too far away to be adjacent
+        // even diagonally, is its own component.
+        assert_ne!(labels[(0, 0)], labels[(3, 3)]);
+    }
+
+    #[test]
+    fn flood_fill_follows_hex_neighbors() {
+        // A single row of hex cells is all mutually adjacent through the `E`/`W` directions
+        // regardless of the "odd-r" row-parity shuffling, since they never leave row 0.
+        let cells = Array2::from_elem((1, 4), true);
+        let region = flood_fill::<_, HexDirection>(cells.view(), (0, 0), |_, _| true);
+        assert_eq!(region.len(), 4);
+    }
+}